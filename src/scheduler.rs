@@ -14,7 +14,35 @@ use std::cmp::Ordering;
 use try_guard::guard;
 
 use crate::time::TimeGenerator;
-use crate::window::MappedWindow;
+use crate::window::{CtxAction, MappedWindow, SchedulerCtx, Window};
+
+/// Sort windows by start time and reject them if any two overlap.
+fn sorted_non_overlapping<'a, T>(
+  mut windows: Vec<MappedWindow<'a, T>>
+) -> Option<Vec<MappedWindow<'a, T>>>
+where T: PartialOrd + Copy {
+  windows.sort_by(|a, b| a.window.start.partial_cmp(&b.window.start).unwrap_or(Ordering::Less));
+
+  // ensure there’s no overlapping
+  let overlapping = windows.iter().zip(windows.iter().skip(1)).any(|(a, b)| {
+    b.window.start < a.window.end
+  });
+  guard!(!overlapping);
+
+  Some(windows)
+}
+
+/// A [`SchedulerCtx`] that rejects dynamic scheduling, used by schedulers that don’t support
+/// enqueueing or cancelling windows while running.
+struct NoCtx;
+
+impl<'a, T> SchedulerCtx<'a, T> for NoCtx {
+  fn schedule(&mut self, _window: Window<T>, _action: CtxAction<'a, T>) -> Option<u64> {
+    None
+  }
+
+  fn cancel(&mut self, _window_id: u64) {}
+}
 
 /// A random-access scheduler.
 ///
@@ -31,8 +59,19 @@ use crate::window::MappedWindow;
 /// > the initial concept of a sequential scheduler (it will run in _O(N)_ at worst).
 pub struct RandomAccessScheduler<'a, G> where G: TimeGenerator {
   time_gen: G,
-  windows: Vec<MappedWindow<'a, G::Time>>,
-  interrupt: Option<Box<FnMut(G::Time) -> Interrupt + 'a>>
+  windows: Vec<IdentifiedWindow<'a, G::Time>>,
+  next_id: u64,
+  loop_mode: LoopMode,
+  interrupt: Option<Box<dyn FnMut(G::Time) -> Interrupt + 'a>>
+}
+
+/// A [`MappedWindow`] tagged with the id it was given when scheduled, so that a running action can
+/// later [`cancel`] it through a [`SchedulerCtx`].
+///
+/// [`cancel`]: SchedulerCtx::cancel
+struct IdentifiedWindow<'a, T> {
+  id: u64,
+  mapped: MappedWindow<'a, T>
 }
 
 impl<'a, G> RandomAccessScheduler<'a, G> where G: TimeGenerator {
@@ -44,30 +83,34 @@ impl<'a, G> RandomAccessScheduler<'a, G> where G: TimeGenerator {
     windows: W
   ) -> Option<Self>
   where W: Into<Vec<MappedWindow<'a, G::Time>>> {
-    let mut windows = windows.into();
-
-    windows.sort_by(|a, b| a.window.start.partial_cmp(&b.window.start).unwrap_or(Ordering::Less));
-    //
-    // ensure there’s no overlapping
-    let overlapping = windows.iter().zip(windows.iter().skip(1)).any(|(a, b)| {
-      b.window.start < a.window.end
-    });
-    guard!(!overlapping);
+    let windows = sorted_non_overlapping(windows.into())?;
+    let next_id = windows.len() as u64;
+    let windows = windows.into_iter()
+      .enumerate()
+      .map(|(id, mapped)| IdentifiedWindow { id: id as u64, mapped })
+      .collect();
 
     Some(RandomAccessScheduler {
       time_gen,
       windows,
+      next_id,
+      loop_mode: LoopMode::Once,
       interrupt: None
     })
   }
 
+  /// Set how the schedule should repeat once it reaches the end of its windows.
+  pub fn looping(&mut self, loop_mode: LoopMode) {
+    self.loop_mode = loop_mode;
+  }
+
   fn active_window_index(&self, t: G::Time) -> Option<usize> {
     self.windows.binary_search_by(|win| {
-      match win.window.start.partial_cmp(&t).unwrap_or(Ordering::Less) {
+      match win.mapped.window.start.partial_cmp(&t).unwrap_or(Ordering::Less) {
         Ordering::Equal => Ordering::Equal,
         Ordering::Greater => Ordering::Greater,
 
-        Ordering::Less => match t.partial_cmp(&win.window.end).unwrap_or(Ordering::Less) {
+        Ordering::Less => match t.partial_cmp(&win.mapped.window.end).unwrap_or(Ordering::Less) {
           Ordering::Less | Ordering::Equal => Ordering::Equal,
           Ordering::Greater => Ordering::Less
         }
@@ -76,26 +119,287 @@ impl<'a, G> RandomAccessScheduler<'a, G> where G: TimeGenerator {
   }
 
   /// Schedule the mapped windows.
+  ///
+  /// Depending on the scheduler’s [`LoopMode`] (see [`looping`]), the schedule may run several
+  /// times over, resetting the time generator to its origin in between runs and firing the mapped
+  /// `carry` closures again with the loop-local time. An interruption always stops the schedule
+  /// for good, regardless of the loop mode.
+  ///
+  /// [`looping`]: RandomAccessScheduler::looping
   pub fn schedule(&mut self) {
+    if let LoopMode::Count(0) = self.loop_mode {
+      return;
+    }
+
+    let mut runs = 0u32;
+
+    loop {
+      if let Interrupt::Break = self.run_once() {
+        break;
+      }
+
+      runs += 1;
+
+      let keep_looping = match self.loop_mode {
+        LoopMode::Once => false,
+        LoopMode::Count(count) => runs < count,
+        LoopMode::Forever => true
+      };
+
+      if !keep_looping {
+        break;
+      }
+    }
+  }
+
+  /// Run the schedule once, from the time generator’s origin to the end of the last window.
+  ///
+  /// Returns [`Interrupt::Break`] if the interrupt function fired, [`Interrupt::Continue`]
+  /// otherwise.
+  fn run_once(&mut self) -> Interrupt {
     self.time_gen.reset();
     let mut t = self.time_gen.current();
 
     loop {
       if let Some(ref mut interrupt) = self.interrupt {
         if let Interrupt::Break = (interrupt)(t) {
-          break;
+          return Interrupt::Break;
         }
       }
 
       let win_ix = self.active_window_index(t);
 
       if let Some(win_ix) = win_ix {
-        ((&mut self.windows[win_ix]).carry)(t);
+        // split around the active window instead of removing it, so the common path (an action
+        // that never touches the ctx) doesn’t pay for a vec shift and a full re-sort
+        let (before, at_and_after) = self.windows.split_at_mut(win_ix);
+        let (active, after) = at_and_after.split_at_mut(1);
+        let active = &mut active[0];
+        let active_id = active.id;
+        let active_window = active.mapped.window;
+
+        let mut pending = Vec::new();
+        let mut cancelled = Vec::new();
+
+        {
+          let mut ctx = RandomAccessCtx {
+            before,
+            active_id,
+            active_window,
+            after,
+            next_id: &mut self.next_id,
+            pending: &mut pending,
+            cancelled: &mut cancelled
+          };
+
+          (active.mapped.carry)(&mut ctx, t);
+        }
+
+        // only churn the vec (and lose the sorted invariant binary search relies on) when the
+        // action actually scheduled or cancelled a window
+        if !cancelled.is_empty() {
+          self.windows.retain(|iw| !cancelled.contains(&iw.id));
+          // a window can be cancelled within the same action that scheduled it, before it ever
+          // lands in `self.windows`, so `pending` needs to be filtered too
+          pending.retain(|iw| !cancelled.contains(&iw.id));
+        }
+
+        if !pending.is_empty() {
+          self.windows.append(&mut pending);
+        }
+
+        if !cancelled.is_empty() || !pending.is_empty() {
+          self.windows.sort_by(|a, b| {
+            a.mapped.window.start.partial_cmp(&b.mapped.window.start).unwrap_or(Ordering::Less)
+          });
+        }
       }
 
       self.time_gen.tick();
       t = self.time_gen.current();
 
+      // check whether the simulation is done
+      if let Some(last_win) = self.windows.last() {
+        if t >= last_win.mapped.window.end {
+          break
+        }
+      }
+    }
+
+    Interrupt::Continue
+  }
+
+  /// Make the scheduler interruptible with the given function
+  ///
+  /// > Note: the function must not block and return as soon as possible.
+  pub fn interruptible_with<F>(&mut self, interrupt: F) where F: FnMut(G::Time) -> Interrupt + 'a {
+    self.interrupt = Some(Box::new(interrupt));
+  }
+}
+
+/// Whether two windows overlap.
+fn windows_overlap<T>(a: &Window<T>, b: &Window<T>) -> bool where T: PartialOrd + Copy {
+  a.start < b.end && b.start < a.end
+}
+
+/// The [`SchedulerCtx`] handed to actions run by a [`RandomAccessScheduler`].
+///
+/// It lets a running action enqueue new, non-overlapping windows and cancel windows that are still
+/// pending. New windows and cancellations are only staged here (in `pending` / `cancelled`); the
+/// scheduler applies them to its window list once the action returns, so that overlap validation
+/// can see the window currently running (`active_window`) alongside every other scheduled and
+/// pending window.
+struct RandomAccessCtx<'a, 'c, T> {
+  before: &'c [IdentifiedWindow<'a, T>],
+  active_id: u64,
+  active_window: Window<T>,
+  after: &'c [IdentifiedWindow<'a, T>],
+  next_id: &'c mut u64,
+  pending: &'c mut Vec<IdentifiedWindow<'a, T>>,
+  cancelled: &'c mut Vec<u64>
+}
+
+impl<'a, 'c, T> SchedulerCtx<'a, T> for RandomAccessCtx<'a, 'c, T> where T: PartialOrd + Copy {
+  fn schedule(&mut self, window: Window<T>, action: CtxAction<'a, T>) -> Option<u64> {
+    let cancelled = &*self.cancelled;
+
+    let overlaps_other = |iw: &IdentifiedWindow<'a, T>| {
+      !cancelled.contains(&iw.id) && windows_overlap(&window, &iw.mapped.window)
+    };
+
+    let overlaps =
+      self.before.iter().any(overlaps_other) ||
+      (!cancelled.contains(&self.active_id) && windows_overlap(&window, &self.active_window)) ||
+      self.after.iter().any(overlaps_other) ||
+      self.pending.iter().any(|iw| windows_overlap(&window, &iw.mapped.window));
+
+    guard!(!overlaps);
+
+    let id = *self.next_id;
+    *self.next_id += 1;
+
+    self.pending.push(IdentifiedWindow { id, mapped: MappedWindow { window, carry: action } });
+
+    Some(id)
+  }
+
+  fn cancel(&mut self, window_id: u64) {
+    self.cancelled.push(window_id);
+  }
+}
+
+/// A sequential scheduler.
+///
+/// Unlike [`RandomAccessScheduler`], which resolves the active window from scratch on every query,
+/// a sequential scheduler keeps a cursor into the sorted windows and moves it forward as time
+/// passes it by (and back when unticking or resetting). As long as you keep ticking (or unticking)
+/// with a small delta instead of jumping around, advancing the cursor is amortized _O(1)_ rather
+/// than _O(log N)_.
+///
+/// > Note: if you use a sequential scheduler by doing random-accesses, you are basically ruining
+/// > the initial concept of a sequential scheduler (it will run in _O(N)_ at worst).
+///
+/// Because a single [`tick`] can step over several windows at once — for instance if the
+/// [`TimeGenerator`] has a large delta — a sequential scheduler still invokes the `carry` of every
+/// window it steps over completely at least once, at that window’s `start`, so that no animation
+/// segment is silently dropped.
+///
+/// [`tick`]: SequentialScheduler::tick
+pub struct SequentialScheduler<'a, G> where G: TimeGenerator {
+  time_gen: G,
+  windows: Vec<MappedWindow<'a, G::Time>>,
+  cursor: usize,
+  interrupt: Option<Box<dyn FnMut(G::Time) -> Interrupt + 'a>>
+}
+
+impl<'a, G> SequentialScheduler<'a, G> where G: TimeGenerator {
+  /// Create a new sequential scheduler.
+  ///
+  /// This function might fail if the time windows are overlapping.
+  pub fn new<W>(
+    time_gen: G,
+    windows: W
+  ) -> Option<Self>
+  where W: Into<Vec<MappedWindow<'a, G::Time>>> {
+    let windows = sorted_non_overlapping(windows.into())?;
+
+    Some(SequentialScheduler {
+      time_gen,
+      windows,
+      cursor: 0,
+      interrupt: None
+    })
+  }
+
+  fn active_window_index(&self, t: G::Time) -> Option<usize> {
+    self.windows.get(self.cursor).filter(|win| t >= win.window.start && t < win.window.end)?;
+    Some(self.cursor)
+  }
+
+  /// Tick time forward.
+  ///
+  /// This advances the cursor while it points at a window whose `end` has already been passed. Any
+  /// window that is stepped over entirely (i.e. its whole `[start, end)` lies inside
+  /// `(t_prev, t]`) still gets its `carry` invoked once, at its `start`, so no window is skipped
+  /// silently.
+  pub fn tick(&mut self) -> G::Time {
+    let t_prev = self.time_gen.current();
+    self.time_gen.tick();
+    let t = self.time_gen.current();
+
+    while self.cursor < self.windows.len() && t >= self.windows[self.cursor].window.end {
+      let win = &mut self.windows[self.cursor];
+
+      if win.window.start > t_prev {
+        (win.carry)(&mut NoCtx, win.window.start);
+      }
+
+      self.cursor += 1;
+    }
+
+    t
+  }
+
+  /// Tick time backwards.
+  ///
+  /// This moves the cursor back while it points past a window that has not ended yet at the current
+  /// time (one that contains it or lies ahead of it), so that the cursor stays consistent after
+  /// unticking.
+  pub fn untick(&mut self) -> G::Time {
+    self.time_gen.untick();
+    let t = self.time_gen.current();
+
+    while self.cursor > 0 && t < self.windows[self.cursor - 1].window.end {
+      self.cursor -= 1;
+    }
+
+    t
+  }
+
+  /// Reset the generator and the cursor to their initial values.
+  pub fn reset(&mut self) {
+    self.time_gen.reset();
+    self.cursor = 0;
+  }
+
+  /// Schedule the mapped windows.
+  pub fn schedule(&mut self) {
+    self.reset();
+    let mut t = self.time_gen.current();
+
+    loop {
+      if let Some(ref mut interrupt) = self.interrupt {
+        if let Interrupt::Break = (interrupt)(t) {
+          break;
+        }
+      }
+
+      if let Some(win_ix) = self.active_window_index(t) {
+        (self.windows[win_ix].carry)(&mut NoCtx, t);
+      }
+
+      t = self.tick();
+
       // check whether the simulation is done
       if let Some(last_win) = self.windows.last() {
         if t >= last_win.window.end {
@@ -113,6 +417,173 @@ impl<'a, G> RandomAccessScheduler<'a, G> where G: TimeGenerator {
   }
 }
 
+/// An entry waiting in a [`WheelScheduler`] slot for its activation round.
+struct WheelEntry {
+  window_ix: usize,
+  rounds: u32
+}
+
+/// A hashed timing-wheel scheduler.
+///
+/// [`RandomAccessScheduler`] resolves in _O(log N)_ and [`SequentialScheduler`] in amortized
+/// _O(1)_, but both still hold every window in a flat, sorted [`Vec`]. When you have tens of
+/// thousands of windows and only ever play them forward, a hashed timing wheel does better: each
+/// window is bucketed once, up front, into one of `N` slots (`N` a power of two) based on the tick
+/// at which it activates, and advancing the wheel by one slot is _O(1)_ independently of how many
+/// windows are scheduled.
+///
+/// The trade-off is that a wheel is forward-only: rewinding time would require rebuilding it from
+/// scratch, so there is no `untick`.
+///
+/// Bucketing a window requires converting its bounds to an `f64` tick count, hence the
+/// `G::Time: Into<f64>` bound. That makes [`WheelScheduler`] incompatible with [`FixedTime`],
+/// which deliberately has no `Into<f64>` impl to stay drift-free: the two highest-precision
+/// features of this crate don't compose. Reach for [`RandomAccessScheduler`] or
+/// [`SequentialScheduler`] if you need [`FixedTime`]'s exactness.
+///
+/// [`FixedTime`]: crate::time::fixed::FixedTime
+pub struct WheelScheduler<'a, G> where G: TimeGenerator, G::Time: Into<f64> {
+  time_gen: G,
+  windows: Vec<MappedWindow<'a, G::Time>>,
+  mask: usize,
+  wheel: Vec<Vec<WheelEntry>>,
+  cursor: usize,
+  active: Vec<usize>,
+  interrupt: Option<Box<dyn FnMut(G::Time) -> Interrupt + 'a>>
+}
+
+impl<'a, G> WheelScheduler<'a, G> where G: TimeGenerator, G::Time: Into<f64> {
+  /// Create a new wheel scheduler.
+  ///
+  /// `slots` is the number of slots of the wheel and must be a power of two. `delta` is the tick
+  /// duration used to compute the activation slot of each window, and should match the delta of
+  /// `time_gen`.
+  ///
+  /// This function might fail if `slots` is not a power of two or if the time windows are
+  /// overlapping.
+  pub fn new<W>(
+    time_gen: G,
+    delta: G::Time,
+    slots: usize,
+    windows: W
+  ) -> Option<Self>
+  where W: Into<Vec<MappedWindow<'a, G::Time>>> {
+    guard!(slots.is_power_of_two());
+
+    let windows = sorted_non_overlapping(windows.into())?;
+    let origin = time_gen.current().into();
+    let delta = delta.into();
+    guard!(delta > 0.);
+
+    let mask = slots - 1;
+    let log2_slots = slots.trailing_zeros();
+    let mut wheel: Vec<Vec<WheelEntry>> = (0..slots).map(|_| Vec::new()).collect();
+
+    for (window_ix, win) in windows.iter().enumerate() {
+      let k = (((win.window.start.into() - origin) / delta).floor().max(0.)) as u64;
+      wheel[k as usize & mask].push(WheelEntry { window_ix, rounds: (k >> log2_slots) as u32 });
+    }
+
+    let mut scheduler = WheelScheduler {
+      time_gen,
+      windows,
+      mask,
+      wheel,
+      cursor: 0,
+      active: Vec::new(),
+      interrupt: None
+    };
+
+    scheduler.activate_current_slot();
+
+    Some(scheduler)
+  }
+
+  /// Pop due entries out of the slot under the cursor into the active set, ageing the others by
+  /// one rotation.
+  fn activate_current_slot(&mut self) {
+    let bucket = &mut self.wheel[self.cursor];
+    let mut i = 0;
+
+    while i < bucket.len() {
+      if bucket[i].rounds == 0 {
+        let entry = bucket.swap_remove(i);
+        self.active.push(entry.window_ix);
+      } else {
+        bucket[i].rounds -= 1;
+        i += 1;
+      }
+    }
+  }
+
+  /// Tick time forward, advancing the wheel by one slot.
+  pub fn tick(&mut self) -> G::Time {
+    let t = self.time_gen.tick();
+    self.cursor = (self.cursor + 1) & self.mask;
+    self.activate_current_slot();
+
+    t
+  }
+
+  /// Schedule the mapped windows.
+  pub fn schedule(&mut self) {
+    loop {
+      let t = self.time_gen.current();
+
+      if let Some(ref mut interrupt) = self.interrupt {
+        if let Interrupt::Break = (interrupt)(t) {
+          break;
+        }
+      }
+
+      let windows = &mut self.windows;
+      self.active.retain(|&window_ix| {
+        let win = &mut windows[window_ix];
+
+        if t < win.window.end {
+          (win.carry)(&mut NoCtx, t);
+          true
+        } else {
+          false
+        }
+      });
+
+      let t = self.tick();
+
+      // check whether the simulation is done
+      if let Some(last_win) = self.windows.last() {
+        if t >= last_win.window.end {
+          break
+        }
+      }
+    }
+  }
+
+  /// Make the scheduler interruptible with the given function
+  ///
+  /// > Note: the function must not block and return as soon as possible.
+  pub fn interruptible_with<F>(&mut self, interrupt: F) where F: FnMut(G::Time) -> Interrupt + 'a {
+    self.interrupt = Some(Box::new(interrupt));
+  }
+}
+
+/// How many times a schedule should run.
+///
+/// Set on a scheduler through e.g. [`RandomAccessScheduler::looping`] to turn a one-shot timeline
+/// into a cyclic animation (an idle loop, a ticking clock, …) without having to rebuild its windows
+/// with [`Window::repeat`].
+///
+/// [`Window::repeat`]: crate::window::Window::repeat
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LoopMode {
+  /// Run the schedule once, then stop.
+  Once,
+  /// Run the schedule `count` times.
+  Count(u32),
+  /// Run the schedule forever, until interrupted.
+  Forever
+}
+
 /// Interruption mechanism.
 ///
 /// A scheduler has to check when an interruption has occurred. If one does, it must return from the
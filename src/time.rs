@@ -17,6 +17,8 @@
 //!
 //! [`TimeGenerator`]: crate::time::TimeGenerator
 
+pub mod fixed;
+pub mod pausable;
 pub mod simple;
 
 /// Set of types that can handle time.
@@ -49,4 +51,29 @@ pub trait TimeGenerator {
 
   /// Change the internal delta.
   fn change_delta(&mut self, delta: Self::Time);
+
+  /// Advance time forward until [`current`] reaches `target`.
+  ///
+  /// The default implementation repeatedly calls [`tick`] until `current()` has caught up with
+  /// `target`, which is _O(N)_ in the number of ticks needed. Implementors for which jumping
+  /// straight to a target time is cheaper, such as [`SimpleF32TimeGenerator`], should override it.
+  ///
+  /// This is forward-only: a `target` behind `current` is a no-op, it will not [`untick`]. It also
+  /// bails out as soon as a [`tick`] fails to make progress — for instance because the generator is
+  /// paused — instead of spinning forever.
+  ///
+  /// [`current`]: TimeGenerator::current
+  /// [`tick`]: TimeGenerator::tick
+  /// [`untick`]: TimeGenerator::untick
+  /// [`SimpleF32TimeGenerator`]: crate::time::simple::SimpleF32TimeGenerator
+  fn advance_to(&mut self, target: Self::Time) {
+    while self.current() < target {
+      let before = self.current();
+      self.tick();
+
+      if self.current() <= before {
+        break;
+      }
+    }
+  }
 }
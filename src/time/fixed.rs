@@ -0,0 +1,118 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::time::TimeGenerator;
+
+const FEMTOS_PER_SECOND: i128 = 1_000_000_000_000_000;
+
+/// A point in time (or a duration) expressed as an exact number of femtoseconds.
+///
+/// Unlike `f32`-based time, which drifts because `tick`/`untick` repeatedly add or subtract
+/// `delta`, [`FixedTime`] stores its value as an `i128`, so ticking and unticking stay exact and
+/// reversible even over millions of steps.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FixedTime(i128);
+
+impl FixedTime {
+  /// The zero duration / origin of time.
+  pub const ZERO: FixedTime = FixedTime(0);
+
+  /// Build a [`FixedTime`] from a number of seconds.
+  pub fn from_seconds(seconds: f64) -> Self {
+    FixedTime((seconds * FEMTOS_PER_SECOND as f64).round() as i128)
+  }
+
+  /// Build a [`FixedTime`] from a frequency expressed in Hz, yielding its period.
+  pub fn from_hz(hz: f64) -> Self {
+    Self::from_seconds(1. / hz)
+  }
+
+  /// Number of femtoseconds this [`FixedTime`] represents.
+  pub fn as_femtos(self) -> i128 {
+    self.0
+  }
+
+  /// Convert this [`FixedTime`] to seconds.
+  pub fn as_seconds(self) -> f64 {
+    self.0 as f64 / FEMTOS_PER_SECOND as f64
+  }
+}
+
+impl Add for FixedTime {
+  type Output = FixedTime;
+
+  fn add(self, rhs: FixedTime) -> Self::Output {
+    FixedTime(self.0 + rhs.0)
+  }
+}
+
+impl Sub for FixedTime {
+  type Output = FixedTime;
+
+  fn sub(self, rhs: FixedTime) -> Self::Output {
+    FixedTime(self.0 - rhs.0)
+  }
+}
+
+impl Mul<i128> for FixedTime {
+  type Output = FixedTime;
+
+  fn mul(self, rhs: i128) -> Self::Output {
+    FixedTime(self.0 * rhs)
+  }
+}
+
+/// A high-precision [`TimeGenerator`] based on [`FixedTime`].
+///
+/// Where [`SimpleF32TimeGenerator`] accumulates `f32` rounding error over many ticks,
+/// [`FixedTimeGenerator`] keeps `current`, `reset_value` and `delta` as exact femtosecond counts,
+/// so long-running schedules never drift out of alignment with their window boundaries.
+///
+/// [`SimpleF32TimeGenerator`]: crate::time::simple::SimpleF32TimeGenerator
+pub struct FixedTimeGenerator {
+  current: FixedTime,
+  reset_value: FixedTime,
+  delta: FixedTime
+}
+
+impl FixedTimeGenerator {
+  /// Create a new [`FixedTimeGenerator`].
+  pub fn new(reset_value: FixedTime, delta: FixedTime) -> Self {
+    FixedTimeGenerator {
+      current: reset_value,
+      reset_value,
+      delta
+    }
+  }
+}
+
+impl TimeGenerator for FixedTimeGenerator {
+  type Time = FixedTime;
+
+  fn current(&self) -> Self::Time {
+    self.current
+  }
+
+  fn tick(&mut self) -> Self::Time {
+    let t = self.current;
+    self.current = self.current + self.delta;
+    t
+  }
+
+  fn untick(&mut self) -> Self::Time {
+    let t = self.current;
+    self.current = self.current - self.delta;
+    t
+  }
+
+  fn reset(&mut self) {
+    self.set(self.reset_value);
+  }
+
+  fn set(&mut self, value: Self::Time) {
+    self.current = value;
+  }
+
+  fn change_delta(&mut self, delta: Self::Time) {
+    self.delta = delta;
+  }
+}
@@ -0,0 +1,91 @@
+use std::ops::Mul;
+
+use crate::time::TimeGenerator;
+
+/// A [`TimeGenerator`] wrapper that can be paused, resumed, slowed down or sped up without
+/// rewriting the underlying generator.
+///
+/// While paused, [`tick`] and [`untick`] leave [`current`] unchanged. The playback [`set_scale`]
+/// is applied to the base delta given at construction time on every [`tick`] / [`untick`], so a
+/// scale of `1.` plays at normal speed, `< 1.` slows down and `> 1.` speeds up.
+///
+/// [`tick`]: TimeGenerator::tick
+/// [`untick`]: TimeGenerator::untick
+/// [`current`]: TimeGenerator::current
+/// [`set_scale`]: PausableTimeGenerator::set_scale
+pub struct PausableTimeGenerator<G> where G: TimeGenerator, G::Time: Mul<f32, Output = G::Time> {
+  inner: G,
+  base_delta: G::Time,
+  scale: f32,
+  paused: bool
+}
+
+impl<G> PausableTimeGenerator<G> where G: TimeGenerator, G::Time: Mul<f32, Output = G::Time> {
+  /// Wrap `inner`, using `base_delta` as the unscaled delta to apply on every tick / untick.
+  pub fn new(inner: G, base_delta: G::Time) -> Self {
+    PausableTimeGenerator {
+      inner,
+      base_delta,
+      scale: 1.,
+      paused: false
+    }
+  }
+
+  /// Pause the generator.
+  pub fn pause(&mut self) {
+    self.paused = true;
+  }
+
+  /// Resume a paused generator.
+  pub fn resume(&mut self) {
+    self.paused = false;
+  }
+
+  /// Is the generator currently paused?
+  pub fn is_paused(&self) -> bool {
+    self.paused
+  }
+
+  /// Set the playback scale applied to the base delta on every tick / untick.
+  pub fn set_scale(&mut self, scale: f32) {
+    self.scale = scale;
+  }
+}
+
+impl<G> TimeGenerator for PausableTimeGenerator<G> where G: TimeGenerator, G::Time: Mul<f32, Output = G::Time> {
+  type Time = G::Time;
+
+  fn current(&self) -> Self::Time {
+    self.inner.current()
+  }
+
+  fn tick(&mut self) -> Self::Time {
+    if self.paused {
+      return self.inner.current();
+    }
+
+    self.inner.change_delta(self.base_delta * self.scale);
+    self.inner.tick()
+  }
+
+  fn untick(&mut self) -> Self::Time {
+    if self.paused {
+      return self.inner.current();
+    }
+
+    self.inner.change_delta(self.base_delta * self.scale);
+    self.inner.untick()
+  }
+
+  fn reset(&mut self) {
+    self.inner.reset();
+  }
+
+  fn set(&mut self, value: Self::Time) {
+    self.inner.set(value);
+  }
+
+  fn change_delta(&mut self, delta: Self::Time) {
+    self.base_delta = delta;
+  }
+}
@@ -48,5 +48,11 @@ impl TimeGenerator for SimpleF32TimeGenerator {
   fn change_delta(&mut self, delta: Self::Time) {
     self.delta = delta;
   }
+
+  fn advance_to(&mut self, target: Self::Time) {
+    if target > self.current {
+      self.current = target;
+    }
+  }
 }
 
@@ -11,6 +11,8 @@
 //! [`Window`]: crate::window::Window
 //! [`MappedWindow`]: crate::window::MappedWindow
 
+use std::ops::Add;
+
 #[cfg(feature = "json")] use serde::{Deserialize, Serialize};
 
 /// A pure time window.
@@ -33,7 +35,22 @@ impl<T> Window<T> {
   }
 
   /// Map an action to perform inside the [`Window`].
-  pub fn map<'a, F>(self, f: F) -> MappedWindow<'a, T> where F: FnMut(T) + 'a {
+  pub fn map<'a, F>(self, mut f: F) -> MappedWindow<'a, T> where F: FnMut(T) + 'a {
+    MappedWindow {
+      window: self,
+      carry: Box::new(move |_ctx, t| f(t))
+    }
+  }
+
+  /// Map a context-aware action to perform inside the [`Window`].
+  ///
+  /// Unlike [`map`], the action is given a [`SchedulerCtx`], letting it react to the running
+  /// schedule — for instance enqueueing a follow-up window once this one is about to end, or
+  /// cancelling a window that is still pending.
+  ///
+  /// [`map`]: Window::map
+  pub fn map_ctx<'a, F>(self, f: F) -> MappedWindow<'a, T>
+  where F: FnMut(&mut dyn SchedulerCtx<'a, T>, T) + 'a {
     MappedWindow {
       window: self,
       carry: Box::new(f)
@@ -41,9 +58,68 @@ impl<T> Window<T> {
   }
 }
 
+impl<T> Window<T> where T: Add<Output = T> + Copy {
+  /// Repeat this [`Window`] `count` times, each repetition shifted forward by `period` compared to
+  /// the previous one.
+  ///
+  /// This is a cheap way to express cyclic timelines (an idle loop, a ticking clock, …) without
+  /// having to clone and re-offset windows by hand.
+  pub fn repeat(self, count: u32, period: T) -> Repeat<T> {
+    Repeat {
+      window: self,
+      period,
+      remaining: count
+    }
+  }
+}
+
+/// Iterator returned by [`Window::repeat`], yielding the original window followed by copies of it
+/// shifted forward by `period`, `2 * period`, etc.
+pub struct Repeat<T> {
+  window: Window<T>,
+  period: T,
+  remaining: u32
+}
+
+impl<T> Iterator for Repeat<T> where T: Add<Output = T> + Copy {
+  type Item = Window<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.remaining == 0 {
+      return None;
+    }
+
+    let current = self.window;
+    self.window = Window::new(self.window.start + self.period, self.window.end + self.period);
+    self.remaining -= 1;
+
+    Some(current)
+  }
+}
+
 /// Action scoped to time windows.
 pub struct MappedWindow<'a, T> {
   /// Window into which execute an action.
   pub window: Window<T>,
-  pub(crate) carry: Box<dyn FnMut(T) + 'a>
+  pub(crate) carry: CtxAction<'a, T>
+}
+
+/// Action scheduled via [`Window::map_ctx`].
+pub type CtxAction<'a, T> = Box<dyn FnMut(&mut dyn SchedulerCtx<'a, T>, T) + 'a>;
+
+/// Context given to an action mapped with [`Window::map_ctx`], letting it react to the running
+/// schedule instead of merely observing time.
+///
+/// [`Window::map_ctx`]: crate::window::Window::map_ctx
+pub trait SchedulerCtx<'a, T> {
+  /// Enqueue a new window to run during the current schedule.
+  ///
+  /// Returns the id given to the new window if it was accepted, or `None` if it overlaps a
+  /// window that is already scheduled or pending.
+  fn schedule(&mut self, window: Window<T>, action: CtxAction<'a, T>) -> Option<u64>;
+
+  /// Cancel a pending window by the id returned from [`schedule`].
+  ///
+  /// [`schedule`]: SchedulerCtx::schedule
+  fn cancel(&mut self, window_id: u64);
 }
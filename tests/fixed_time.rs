@@ -0,0 +1,47 @@
+use awoo::time::TimeGenerator;
+use awoo::time::fixed::{FixedTime, FixedTimeGenerator};
+
+#[test]
+fn ticking_is_exact_over_many_steps() {
+  let delta = FixedTime::from_seconds(0.1);
+  let mut gen = FixedTimeGenerator::new(FixedTime::ZERO, delta);
+
+  // unlike f32, adding the same delta 10_000 times must land on an exact multiple, not something
+  // that merely rounds to it
+  for _ in 0..10_000 {
+    gen.tick();
+  }
+
+  assert_eq!(gen.current(), FixedTime::from_seconds(1_000.));
+  assert_eq!(gen.current().as_femtos(), delta.as_femtos() * 10_000);
+}
+
+#[test]
+fn tick_then_untick_returns_to_the_exact_origin() {
+  let delta = FixedTime::from_hz(3.);
+  let mut gen = FixedTimeGenerator::new(FixedTime::ZERO, delta);
+
+  for _ in 0..1_000 {
+    gen.tick();
+  }
+
+  for _ in 0..1_000 {
+    gen.untick();
+  }
+
+  assert_eq!(gen.current(), FixedTime::ZERO);
+}
+
+#[test]
+fn reset_restores_the_exact_reset_value() {
+  let origin = FixedTime::from_seconds(2.5);
+  let mut gen = FixedTimeGenerator::new(origin, FixedTime::from_seconds(0.1));
+
+  for _ in 0..37 {
+    gen.tick();
+  }
+
+  gen.reset();
+
+  assert_eq!(gen.current(), origin);
+}
@@ -0,0 +1,63 @@
+use awoo::time::TimeGenerator;
+use awoo::time::pausable::PausableTimeGenerator;
+use awoo::time::simple::SimpleF32TimeGenerator;
+
+#[test]
+fn pause_freezes_current() {
+  let mut gen = PausableTimeGenerator::new(SimpleF32TimeGenerator::new(0., 1.), 1.);
+
+  assert_eq!(gen.tick(), 0.);
+  assert_eq!(gen.current(), 1.);
+
+  gen.pause();
+  assert_eq!(gen.tick(), 1.);
+  assert_eq!(gen.tick(), 1.);
+  assert_eq!(gen.current(), 1.);
+
+  gen.resume();
+  assert_eq!(gen.tick(), 1.);
+  assert_eq!(gen.current(), 2.);
+}
+
+#[test]
+fn scale_speeds_up_and_slows_down() {
+  let mut gen = PausableTimeGenerator::new(SimpleF32TimeGenerator::new(0., 1.), 1.);
+
+  gen.set_scale(2.);
+  gen.tick();
+  assert_eq!(gen.current(), 2.);
+
+  gen.set_scale(0.5);
+  gen.tick();
+  assert_eq!(gen.current(), 2.5);
+}
+
+#[test]
+fn advance_to_moves_forward_to_target() {
+  let mut gen = PausableTimeGenerator::new(SimpleF32TimeGenerator::new(0., 1.), 1.);
+
+  gen.advance_to(5.);
+  assert_eq!(gen.current(), 5.);
+}
+
+#[test]
+fn advance_to_is_a_no_op_when_paused() {
+  let mut gen = PausableTimeGenerator::new(SimpleF32TimeGenerator::new(0., 1.), 1.);
+
+  gen.pause();
+  gen.advance_to(5.);
+
+  // ticking fails to make progress while paused, so advance_to must bail out instead of spinning
+  // forever
+  assert_eq!(gen.current(), 0.);
+}
+
+#[test]
+fn advance_to_with_target_behind_current_is_a_no_op() {
+  let mut gen = PausableTimeGenerator::new(SimpleF32TimeGenerator::new(0., 1.), 1.);
+
+  gen.advance_to(3.);
+  gen.advance_to(1.);
+
+  assert_eq!(gen.current(), 3.);
+}
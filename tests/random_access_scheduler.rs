@@ -0,0 +1,98 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use awoo::scheduler::RandomAccessScheduler;
+use awoo::time::simple::SimpleF32TimeGenerator;
+use awoo::window::Window;
+
+#[test]
+fn action_can_schedule_a_follow_up_window() {
+  let seen = Rc::new(RefCell::new(Vec::new()));
+
+  let seen_a = seen.clone();
+  let a = Window::new(0., 1.).map_ctx(move |ctx, t| {
+    seen_a.borrow_mut().push(("a", t));
+
+    let seen_follow_up = seen_a.clone();
+    ctx.schedule(
+      Window::new(1., 2.),
+      Box::new(move |_ctx, t| seen_follow_up.borrow_mut().push(("follow_up", t)))
+    );
+  });
+
+  let tgen = SimpleF32TimeGenerator::new(0., 0.5);
+  let mut scheduler = RandomAccessScheduler::new(tgen, vec![a]).unwrap();
+
+  scheduler.schedule();
+
+  let seen = seen.borrow();
+  assert!(seen.iter().any(|&(name, _)| name == "a"));
+  assert!(seen.iter().any(|&(name, _)| name == "follow_up"));
+}
+
+#[test]
+fn scheduling_over_the_active_window_is_rejected() {
+  let accepted = Rc::new(RefCell::new(true));
+
+  let accepted_inner = accepted.clone();
+  let a = Window::new(0., 2.).map_ctx(move |ctx, _t| {
+    let id = ctx.schedule(Window::new(1., 3.), Box::new(|_ctx, _t| {}));
+    *accepted_inner.borrow_mut() = id.is_some();
+  });
+
+  let tgen = SimpleF32TimeGenerator::new(0., 0.5);
+  let mut scheduler = RandomAccessScheduler::new(tgen, vec![a]).unwrap();
+
+  scheduler.schedule();
+
+  assert!(!*accepted.borrow(), "a window overlapping its own still-executing window must be rejected");
+}
+
+#[test]
+fn action_can_cancel_a_pending_window() {
+  let fired = Rc::new(RefCell::new(Vec::new()));
+
+  let fired_b = fired.clone();
+  let b = Window::new(1., 2.).map(move |t| fired_b.borrow_mut().push(("b", t)));
+
+  let b_id = 1;
+  let fired_a = fired.clone();
+  let a = Window::new(0., 1.).map_ctx(move |ctx, t| {
+    fired_a.borrow_mut().push(("a", t));
+    ctx.cancel(b_id);
+  });
+
+  let tgen = SimpleF32TimeGenerator::new(0., 0.5);
+  let mut scheduler = RandomAccessScheduler::new(tgen, vec![a, b]).unwrap();
+
+  scheduler.schedule();
+
+  let fired = fired.borrow();
+  assert!(fired.iter().any(|&(name, _)| name == "a"));
+  assert!(!fired.iter().any(|&(name, _)| name == "b"), "a cancelled window must never fire");
+}
+
+#[test]
+fn action_can_cancel_a_window_it_just_scheduled() {
+  let follow_up_fired = Rc::new(RefCell::new(false));
+
+  let follow_up_fired_inner = follow_up_fired.clone();
+  let a = Window::new(0., 1.).map_ctx(move |ctx, _t| {
+    let follow_up_fired = follow_up_fired_inner.clone();
+    let id = ctx.schedule(
+      Window::new(2., 3.),
+      Box::new(move |_ctx, _t| *follow_up_fired.borrow_mut() = true)
+    ).unwrap();
+
+    // cancelling within the same action, before the window ever lands in the committed vec, must
+    // still take effect
+    ctx.cancel(id);
+  });
+
+  let tgen = SimpleF32TimeGenerator::new(0., 0.5);
+  let mut scheduler = RandomAccessScheduler::new(tgen, vec![a]).unwrap();
+
+  scheduler.schedule();
+
+  assert!(!*follow_up_fired.borrow(), "a window cancelled in the same action that scheduled it must never fire");
+}
@@ -0,0 +1,56 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use awoo::scheduler::{LoopMode, RandomAccessScheduler};
+use awoo::time::simple::SimpleF32TimeGenerator;
+use awoo::window::Window;
+
+#[test]
+fn repeat_yields_the_original_window_then_shifted_copies() {
+  let windows: Vec<_> = Window::new(0., 1.).repeat(3, 2.).collect();
+
+  assert_eq!(windows.len(), 3);
+  assert_eq!((windows[0].start, windows[0].end), (0., 1.));
+  assert_eq!((windows[1].start, windows[1].end), (2., 3.));
+  assert_eq!((windows[2].start, windows[2].end), (4., 5.));
+}
+
+#[test]
+fn repeat_with_zero_count_yields_nothing() {
+  let windows: Vec<_> = Window::new(0., 1.).repeat(0, 2.).collect();
+  assert!(windows.is_empty());
+}
+
+#[test]
+fn loop_mode_count_runs_exactly_that_many_times() {
+  let runs = Rc::new(RefCell::new(0));
+
+  let runs_inner = runs.clone();
+  let a = Window::new(0., 1.).map(move |_t| *runs_inner.borrow_mut() += 1);
+
+  // delta == window length, so the carry fires exactly once per run_once, making the carry count
+  // a direct proxy for how many times the schedule ran
+  let tgen = SimpleF32TimeGenerator::new(0., 1.);
+  let mut scheduler = RandomAccessScheduler::new(tgen, vec![a]).unwrap();
+  scheduler.looping(LoopMode::Count(3));
+
+  scheduler.schedule();
+
+  assert_eq!(*runs.borrow(), 3);
+}
+
+#[test]
+fn loop_mode_count_zero_never_runs() {
+  let runs = Rc::new(RefCell::new(0));
+
+  let runs_inner = runs.clone();
+  let a = Window::new(0., 1.).map(move |_t| *runs_inner.borrow_mut() += 1);
+
+  let tgen = SimpleF32TimeGenerator::new(0., 1.);
+  let mut scheduler = RandomAccessScheduler::new(tgen, vec![a]).unwrap();
+  scheduler.looping(LoopMode::Count(0));
+
+  scheduler.schedule();
+
+  assert_eq!(*runs.borrow(), 0);
+}
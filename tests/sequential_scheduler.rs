@@ -0,0 +1,32 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use awoo::scheduler::SequentialScheduler;
+use awoo::time::simple::SimpleF32TimeGenerator;
+use awoo::window::Window;
+
+// A tick large enough to step clean over the middle window in a single `tick()` call must still
+// fire that window's carry once, at its `start`, instead of silently skipping it.
+#[test]
+fn skipped_window_fires_at_start() {
+  let seen = Rc::new(RefCell::new(Vec::new()));
+
+  let seen_a = seen.clone();
+  let a = Window::new(0., 1.).map(move |t| seen_a.borrow_mut().push(("a", t)));
+
+  let seen_b = seen.clone();
+  let b = Window::new(1., 2.).map(move |t| seen_b.borrow_mut().push(("b", t)));
+
+  let seen_c = seen.clone();
+  let c = Window::new(2., 3.).map(move |t| seen_c.borrow_mut().push(("c", t)));
+
+  let tgen = SimpleF32TimeGenerator::new(0., 5.);
+  let mut scheduler = SequentialScheduler::new(tgen, vec![a, b, c]).unwrap();
+
+  scheduler.schedule();
+
+  let seen = seen.borrow();
+  assert!(seen.iter().any(|&(name, t)| name == "a" && t == 0.));
+  assert!(seen.iter().any(|&(name, t)| name == "b" && t == 1.));
+  assert!(seen.iter().any(|&(name, t)| name == "c" && t == 2.));
+}
@@ -47,3 +47,21 @@ fn reset() {
   gen.reset();
   assert!(f32eq(gen.tick(), 0.));
 }
+
+#[test]
+fn advance_to_moves_forward() {
+  let mut gen = SimpleF32TimeGenerator::new(0., 0.1);
+
+  gen.advance_to(3.);
+  assert!(f32eq(gen.current(), 3.));
+}
+
+#[test]
+fn advance_to_does_not_rewind() {
+  let mut gen = SimpleF32TimeGenerator::new(0., 0.1);
+
+  gen.advance_to(3.);
+  gen.advance_to(1.);
+
+  assert!(f32eq(gen.current(), 3.));
+}
@@ -0,0 +1,35 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use awoo::scheduler::WheelScheduler;
+use awoo::time::simple::SimpleF32TimeGenerator;
+use awoo::window::Window;
+
+// A window bucketed more than `slots` ticks away from the origin must wait a full rotation of the
+// wheel (its `rounds` counter) before it is promoted to the active set, instead of firing early
+// just because the slot index wrapped around.
+#[test]
+fn activation_waits_for_its_round() {
+  let seen = Rc::new(RefCell::new(Vec::new()));
+
+  let seen_soon = seen.clone();
+  let soon = Window::new(1., 2.).map(move |t| seen_soon.borrow_mut().push(("soon", t)));
+
+  // with 4 slots and a delta of 1., this window activates at slot (5 & 3) == 1, the same slot as
+  // `soon`, but only after one extra rotation of the wheel
+  let seen_later = seen.clone();
+  let later = Window::new(5., 6.).map(move |t| seen_later.borrow_mut().push(("later", t)));
+
+  let tgen = SimpleF32TimeGenerator::new(0., 1.);
+  let mut scheduler = WheelScheduler::new(tgen, 1., 4, vec![soon, later]).unwrap();
+
+  scheduler.schedule();
+
+  let seen = seen.borrow();
+  assert_eq!(seen.iter().filter(|&&(name, _)| name == "soon").count(), 1);
+  assert_eq!(seen.iter().filter(|&&(name, _)| name == "later").count(), 1);
+
+  let soon_ix = seen.iter().position(|&(name, _)| name == "soon").unwrap();
+  let later_ix = seen.iter().position(|&(name, _)| name == "later").unwrap();
+  assert!(soon_ix < later_ix, "the closer window must activate before the one a full rotation away");
+}